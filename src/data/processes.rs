@@ -9,6 +9,8 @@ use chrono::prelude::*;
 use ctor::ctor;
 use log::{error, trace};
 use procfs::process::all_processes;
+use procfs::{CpuInfo, KernelStats};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::io::{BufRead, BufReader};
 use std::collections::HashMap;
@@ -18,12 +20,15 @@ pub static PROCESS_FILE_NAME: &str = "processes";
 
 lazy_static! {
     pub static ref TICKS_PER_SECOND: Mutex<u64> = Mutex::new(0);
+    pub static ref PAGE_SIZE: Mutex<u64> = Mutex::new(0);
+    pub static ref BOOT_TIME: Mutex<u64> = Mutex::new(0);
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ProcessesRaw {
     pub time: TimeEnum,
     pub ticks_per_second: u64,
+    pub boot_time: u64,
     pub data: String,
 }
 
@@ -33,6 +38,7 @@ impl ProcessesRaw {
             time: TimeEnum::DateTime(Utc::now()),
             data: String::new(),
             ticks_per_second: 0,
+            boot_time: 0,
         }
     }
 }
@@ -40,11 +46,15 @@ impl ProcessesRaw {
 impl CollectData for ProcessesRaw {
     fn prepare_data_collector(&mut self) -> Result<()> {
         *TICKS_PER_SECOND.lock().unwrap() = procfs::ticks_per_second()? as u64;
+        *PAGE_SIZE.lock().unwrap() = procfs::page_size()? as u64;
+        *BOOT_TIME.lock().unwrap() = KernelStats::new()?.btime;
         Ok(())
     }
 
     fn collect_data(&mut self) -> Result<()> {
         let ticks_per_second: u64 = *TICKS_PER_SECOND.lock().unwrap();
+        let page_size: u64 = *PAGE_SIZE.lock().unwrap();
+        let boot_time: u64 = *BOOT_TIME.lock().unwrap();
         self.time = TimeEnum::DateTime(Utc::now());
         self.data = String::new();
         let processes = match all_processes() {
@@ -62,11 +72,22 @@ impl CollectData for ProcessesRaw {
             };
             let name = pstat.comm;
             let pid = pstat.pid as u64;
+            let ppid = pstat.ppid as u64;
             let time_ticks = pstat.utime + pstat.stime;
-            let process_entry = format!("{};{};{}\n", name, pid, time_ticks);
+            let mem_usage_bytes = pstat.rss as u64 * page_size;
+            /* /proc/[pid]/io is often restricted to the owning user; skip the counters rather than the process */
+            let (read_bytes, write_bytes) = match process.io() {
+                Ok(io) => (io.read_bytes, io.write_bytes),
+                Err(_) => (0, 0),
+            };
+            let process_entry = format!(
+                "{};{};{};{};{};{};{};{}\n",
+                name, pid, time_ticks, mem_usage_bytes, read_bytes, write_bytes, pstat.starttime, ppid
+            );
             self.data.push_str(&process_entry);
         }
         self.ticks_per_second = ticks_per_second;
+        self.boot_time = boot_time;
         trace!("{:#?}", self.data);
         trace!("{:#?}", self.ticks_per_second);
         Ok(())
@@ -98,7 +119,12 @@ impl Processes {
 pub struct SampleEntry {
     pub name: String,
     pub pid: u64,
+    pub ppid: u64,
     pub cpu_time: u64,
+    pub mem_usage_bytes: u64,
+    pub read_bytes: u64,
+    pub write_bytes: u64,
+    pub running_time: u64,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -112,7 +138,13 @@ pub struct ProcessEntry {
 pub struct EndEntry {
     pub name: String,
     pub total_cpu_time: u64,
+    pub mem_usage_bytes: u64,
+    pub mem_percent: f64,
+    pub read_bytes_per_sec: f64,
+    pub write_bytes_per_sec: f64,
+    pub running_time: u64,
     pub entries: Vec<Sample>,
+    pub io_entries: Vec<IoSample>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -121,16 +153,31 @@ pub struct EndEntries {
     pub end_entries: Vec<EndEntry>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct IoSample {
+    pub read_bytes_per_sec: f64,
+    pub write_bytes_per_sec: f64,
+    pub time: TimeEnum,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Sample {
-    pub cpu_time: u64,
+    pub cpu_time: f64,
     pub time: TimeEnum,
 }
 
-pub fn get_values(values: Vec<Processes>) -> Result<String> {
+fn num_cpus() -> u64 {
+    match CpuInfo::new() {
+        Ok(cpu_info) => cpu_info.num_cores() as u64,
+        Err(_) => 1,
+    }
+}
+
+pub fn get_values(values: Vec<Processes>, count: usize) -> Result<String> {
     let value_zero = values[0].clone();
     let time_zero = value_zero.time;
     let ticks_per_second: u64 = *TICKS_PER_SECOND.lock().unwrap();
+    let max_percent = 100.0 * num_cpus() as f64;
     let mut process_map: HashMap<String, ProcessEntry> = HashMap::new();
     let mut total_time: u64 = 1;
     if let TimeEnum::TimeDiff(v) = values.last().unwrap().time - values[0].time {
@@ -174,30 +221,36 @@ pub fn get_values(values: Vec<Processes>) -> Result<String> {
         let mut end_entry = EndEntry {
             name: process.name.clone(),
             total_cpu_time: 0,
+            mem_usage_bytes: 0,
+            mem_percent: 0.0,
+            read_bytes_per_sec: 0.0,
+            write_bytes_per_sec: 0.0,
+            running_time: 0,
             entries: Vec::new(),
+            io_entries: Vec::new(),
         };
         let mut entries: Vec<(TimeEnum, u64)> = process.samples.clone().into_iter().collect();
         entries.sort_by(|(a, _), (c, _)| a.cmp(&c));
         let entry_zero: (TimeEnum, u64) = entries[0].clone();
-        let mut prev_sample = Sample {time: entry_zero.0, cpu_time: entry_zero.1};
+        let mut prev_sample = Sample {time: entry_zero.0, cpu_time: entry_zero.1 as f64};
         let mut prev_time: u64 = 0;
         let mut time_now;
         if let TimeEnum::TimeDiff(v) = prev_sample.time {
             prev_time = v;
         }
         for (time, cpu_time) in &entries {
-            let sample = Sample {cpu_time: *cpu_time, time: *time};
+            let sample = Sample {cpu_time: *cpu_time as f64, time: *time};
             /* End sample */
             let mut end_sample = sample.clone();
 
-            if end_sample.cpu_time as i64 - prev_sample.cpu_time as i64 >= 0 {
+            if end_sample.cpu_time - prev_sample.cpu_time >= 0.0 {
                 /* Update sample based on previous sample */
                 end_sample.cpu_time -= prev_sample.cpu_time;
             } else {
-                end_sample.cpu_time = 0;
+                end_sample.cpu_time = 0.0;
             }
             /* Add to total_cpu_time */
-            end_entry.total_cpu_time += end_sample.cpu_time;
+            end_entry.total_cpu_time += end_sample.cpu_time as u64;
 
             match *time {
                 TimeEnum::TimeDiff(v) => {
@@ -209,9 +262,9 @@ pub fn get_values(values: Vec<Processes>) -> Result<String> {
                 _ => continue,
             }
 
-            /* Percentage utilization */
-            end_sample.cpu_time /= ticks_per_second * (time_now - prev_time);
-            end_sample.cpu_time *= 100;
+            /* Percentage utilization, as a fraction of a single CPU's ticks over the elapsed interval */
+            end_sample.cpu_time = (end_sample.cpu_time / ticks_per_second as f64) / (time_now - prev_time) as f64 * 100.0;
+            end_sample.cpu_time = end_sample.cpu_time.clamp(0.0, max_percent);
 
             prev_time = time_now;
             end_entry.entries.push(end_sample);
@@ -224,13 +277,479 @@ pub fn get_values(values: Vec<Processes>) -> Result<String> {
     /* Order the processes by Total CPU Time per collection time */
     end_values.end_entries.sort_by(|a, b| (b.total_cpu_time).cmp(&(a.total_cpu_time)));
 
-    if end_values.end_entries.len() > 16 {
-        end_values.end_entries = end_values.end_entries[0..15].to_vec();
+    if end_values.end_entries.len() > count {
+        end_values.end_entries.truncate(count);
+    }
+
+    Ok(serde_json::to_string(&end_values)?)
+}
+
+fn total_mem_bytes() -> Result<u64> {
+    Ok(procfs::Meminfo::new()?.mem_total)
+}
+
+fn mem_percent(mem_usage_bytes: u64, total_mem_bytes: u64) -> f64 {
+    if total_mem_bytes > 0 {
+        mem_usage_bytes as f64 / total_mem_bytes as f64 * 100.0
+    } else {
+        0.0
+    }
+}
+
+pub fn get_mem_values(values: Vec<Processes>, count: usize) -> Result<String> {
+    let total_mem_bytes = total_mem_bytes()?;
+    let latest = values.last().unwrap();
+    let mut mem_map: HashMap<String, u64> = HashMap::new();
+    for entry in &latest.entries {
+        *mem_map.entry(entry.name.clone()).or_insert(0) += entry.mem_usage_bytes;
+    }
+
+    let mut end_values: EndEntries = EndEntries {
+        collection_time: latest.time.clone(),
+        end_entries: Vec::new(),
+    };
+
+    for (name, mem_usage_bytes) in mem_map {
+        end_values.end_entries.push(EndEntry {
+            name,
+            total_cpu_time: 0,
+            mem_usage_bytes,
+            mem_percent: mem_percent(mem_usage_bytes, total_mem_bytes),
+            read_bytes_per_sec: 0.0,
+            write_bytes_per_sec: 0.0,
+            running_time: 0,
+            entries: Vec::new(),
+            io_entries: Vec::new(),
+        });
+    }
+
+    /* Order the processes by memory usage per collection time */
+    end_values.end_entries.sort_by(|a, b| (b.mem_usage_bytes).cmp(&(a.mem_usage_bytes)));
+
+    if end_values.end_entries.len() > count {
+        end_values.end_entries.truncate(count);
     }
 
     Ok(serde_json::to_string(&end_values)?)
 }
 
+struct IoSamples {
+    name: String,
+    samples: HashMap<TimeEnum, (u64, u64)>,
+}
+
+pub fn get_io_values(values: Vec<Processes>, count: usize) -> Result<String> {
+    let time_zero = values[0].time;
+    let mut total_time: u64 = 1;
+    if let TimeEnum::TimeDiff(v) = values.last().unwrap().time - values[0].time {
+        if v > 0 {
+            total_time = v;
+        }
+    }
+
+    let mut io_map: HashMap<String, IoSamples> = HashMap::new();
+    for value in &values {
+        let time = value.time - time_zero;
+        for entry in &value.entries {
+            match io_map.get_mut(&entry.name) {
+                Some(io) => {
+                    let mut read_bytes = entry.read_bytes;
+                    let mut write_bytes = entry.write_bytes;
+                    if let Some((r, w)) = io.samples.get(&time) {
+                        read_bytes += r;
+                        write_bytes += w;
+                    }
+                    io.samples.insert(time, (read_bytes, write_bytes));
+                },
+                None => {
+                    let mut io = IoSamples {
+                        name: entry.name.clone(),
+                        samples: HashMap::new(),
+                    };
+                    io.samples.insert(time, (entry.read_bytes, entry.write_bytes));
+                    io_map.insert(entry.name.clone(), io);
+                },
+            }
+        }
+    }
+
+    let mut end_values: EndEntries = EndEntries {
+        collection_time: TimeEnum::TimeDiff(total_time),
+        end_entries: Vec::new(),
+    };
+
+    for (_, io) in io_map.iter() {
+        let mut entries: Vec<(TimeEnum, (u64, u64))> = io.samples.clone().into_iter().collect();
+        entries.sort_by(|(a, _), (c, _)| a.cmp(&c));
+        let (first_time, (first_read, first_write)) = entries[0].clone();
+        let (last_time, (last_read, last_write)) = entries[entries.len() - 1].clone();
+
+        let mut first_secs: u64 = 0;
+        let mut last_secs: u64 = 0;
+        if let TimeEnum::TimeDiff(v) = first_time {
+            first_secs = v;
+        }
+        if let TimeEnum::TimeDiff(v) = last_time {
+            last_secs = v;
+        }
+
+        let (read_bytes_per_sec, write_bytes_per_sec) = if last_secs > first_secs
+            && last_read >= first_read
+            && last_write >= first_write
+        {
+            let elapsed = (last_secs - first_secs) as f64;
+            (
+                (last_read - first_read) as f64 / elapsed,
+                (last_write - first_write) as f64 / elapsed,
+            )
+        } else {
+            (0.0, 0.0)
+        };
+
+        /* Per-interval throughput, mirroring how get_values derives a CPU% time series */
+        let mut io_entries: Vec<IoSample> = Vec::new();
+        let mut prev_sample: (TimeEnum, (u64, u64)) = entries[0].clone();
+        let mut prev_time: u64 = 0;
+        if let TimeEnum::TimeDiff(v) = prev_sample.0 {
+            prev_time = v;
+        }
+        for (time, (read_bytes, write_bytes)) in &entries {
+            let time_now = match *time {
+                TimeEnum::TimeDiff(v) => v,
+                _ => continue,
+            };
+            if time_now - prev_time == 0 {
+                continue;
+            }
+            let elapsed = (time_now - prev_time) as f64;
+            let (prev_read, prev_write) = prev_sample.1;
+            let sample_read_bytes_per_sec = if *read_bytes >= prev_read {
+                (*read_bytes - prev_read) as f64 / elapsed
+            } else {
+                0.0
+            };
+            let sample_write_bytes_per_sec = if *write_bytes >= prev_write {
+                (*write_bytes - prev_write) as f64 / elapsed
+            } else {
+                0.0
+            };
+            io_entries.push(IoSample {
+                read_bytes_per_sec: sample_read_bytes_per_sec,
+                write_bytes_per_sec: sample_write_bytes_per_sec,
+                time: *time,
+            });
+
+            prev_time = time_now;
+            prev_sample = (*time, (*read_bytes, *write_bytes));
+        }
+
+        end_values.end_entries.push(EndEntry {
+            name: io.name.clone(),
+            total_cpu_time: 0,
+            mem_usage_bytes: 0,
+            mem_percent: 0.0,
+            read_bytes_per_sec,
+            write_bytes_per_sec,
+            running_time: 0,
+            entries: Vec::new(),
+            io_entries,
+        });
+    }
+
+    /* Order the processes by total I/O throughput per collection time */
+    end_values.end_entries.sort_by(|a, b| {
+        let a_total = a.read_bytes_per_sec + a.write_bytes_per_sec;
+        let b_total = b.read_bytes_per_sec + b.write_bytes_per_sec;
+        b_total.partial_cmp(&a_total).unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    if end_values.end_entries.len() > count {
+        end_values.end_entries.truncate(count);
+    }
+
+    Ok(serde_json::to_string(&end_values)?)
+}
+
+/* Guard against bogus starttime values (e.g. the process start resolving to the Unix epoch,
+ * or a negative/zero duration) the way bottom does, rather than reporting a nonsensical age. */
+fn running_time_secs(ticks_per_second: u64, boot_time: u64, starttime_ticks: u64, now_secs: i64) -> u64 {
+    if ticks_per_second == 0 {
+        return 0;
+    }
+    let start_unix_secs = boot_time + starttime_ticks / ticks_per_second;
+    if start_unix_secs == 0 {
+        return 0;
+    }
+    let now_secs = now_secs.max(0) as u64;
+    if now_secs <= start_unix_secs {
+        return 0;
+    }
+    now_secs - start_unix_secs
+}
+
+pub fn get_age_values(values: Vec<Processes>, count: usize) -> Result<String> {
+    let latest = values.last().unwrap();
+    let mut age_map: HashMap<String, u64> = HashMap::new();
+    for entry in &latest.entries {
+        let running_time = age_map.entry(entry.name.clone()).or_insert(0);
+        if entry.running_time > *running_time {
+            *running_time = entry.running_time;
+        }
+    }
+
+    let mut end_values: EndEntries = EndEntries {
+        collection_time: latest.time.clone(),
+        end_entries: Vec::new(),
+    };
+
+    for (name, running_time) in age_map {
+        end_values.end_entries.push(EndEntry {
+            name,
+            total_cpu_time: 0,
+            mem_usage_bytes: 0,
+            mem_percent: 0.0,
+            read_bytes_per_sec: 0.0,
+            write_bytes_per_sec: 0.0,
+            running_time,
+            entries: Vec::new(),
+            io_entries: Vec::new(),
+        });
+    }
+
+    /* Order the processes by running time, oldest first, per collection time */
+    end_values.end_entries.sort_by(|a, b| (b.running_time).cmp(&(a.running_time)));
+
+    if end_values.end_entries.len() > count {
+        end_values.end_entries.truncate(count);
+    }
+
+    Ok(serde_json::to_string(&end_values)?)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PidEntry {
+    pub pid: u64,
+    pub ppid: u64,
+    pub name: String,
+    pub total_cpu_time: u64,
+    pub entries: Vec<Sample>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PidEntries {
+    pub collection_time: TimeEnum,
+    pub pid_entries: Vec<PidEntry>,
+}
+
+struct PidProcessEntry {
+    pid: u64,
+    ppid: u64,
+    name: String,
+    samples: HashMap<TimeEnum, u64>,
+}
+
+/* Same per-sample CPU% derivation as get_values, but keyed by PID rather than process name, so
+ * distinct PIDs sharing a `comm` (e.g. worker processes) aren't collapsed into one bucket. */
+pub fn get_values_by_pid(values: Vec<Processes>, count: usize) -> Result<String> {
+    let value_zero = values[0].clone();
+    let time_zero = value_zero.time;
+    let ticks_per_second: u64 = *TICKS_PER_SECOND.lock().unwrap();
+    let max_percent = 100.0 * num_cpus() as f64;
+    let mut pid_map: HashMap<u64, PidProcessEntry> = HashMap::new();
+    let mut total_time: u64 = 1;
+    if let TimeEnum::TimeDiff(v) = values.last().unwrap().time - values[0].time {
+        if v > 0 {
+            total_time = v;
+        }
+    }
+
+    for value in values {
+        for entry in value.entries {
+            let time = value.time - time_zero;
+            match pid_map.get_mut(&entry.pid) {
+                Some(pe) => {
+                    let mut sample_cpu_time: u64 = entry.cpu_time;
+                    match pe.samples.get(&time) {
+                        Some(v) => {
+                            sample_cpu_time += v;
+                        },
+                        None => {},
+                    }
+                    pe.samples.insert(time, sample_cpu_time);
+                },
+                None => {
+                    let mut pid_entry = PidProcessEntry {
+                        pid: entry.pid,
+                        ppid: entry.ppid,
+                        name: entry.name.clone(),
+                        samples: HashMap::new(),
+                    };
+                    pid_entry.samples.insert(time, entry.cpu_time);
+                    pid_map.insert(entry.pid, pid_entry);
+                },
+            }
+        }
+    }
+
+    let mut pid_entries: PidEntries = PidEntries {
+        collection_time: TimeEnum::TimeDiff(total_time),
+        pid_entries: Vec::new(),
+    };
+
+    for (_, process) in pid_map.iter_mut() {
+        let mut pid_entry = PidEntry {
+            pid: process.pid,
+            ppid: process.ppid,
+            name: process.name.clone(),
+            total_cpu_time: 0,
+            entries: Vec::new(),
+        };
+        let mut entries: Vec<(TimeEnum, u64)> = process.samples.clone().into_iter().collect();
+        entries.sort_by(|(a, _), (c, _)| a.cmp(&c));
+        let entry_zero: (TimeEnum, u64) = entries[0].clone();
+        let mut prev_sample = Sample {time: entry_zero.0, cpu_time: entry_zero.1 as f64};
+        let mut prev_time: u64 = 0;
+        let mut time_now;
+        if let TimeEnum::TimeDiff(v) = prev_sample.time {
+            prev_time = v;
+        }
+        for (time, cpu_time) in &entries {
+            let sample = Sample {cpu_time: *cpu_time as f64, time: *time};
+            let mut end_sample = sample.clone();
+
+            if end_sample.cpu_time - prev_sample.cpu_time >= 0.0 {
+                end_sample.cpu_time -= prev_sample.cpu_time;
+            } else {
+                end_sample.cpu_time = 0.0;
+            }
+            pid_entry.total_cpu_time += end_sample.cpu_time as u64;
+
+            match *time {
+                TimeEnum::TimeDiff(v) => {
+                    time_now = v;
+                    if time_now - prev_time == 0 {
+                        continue;
+                    }
+                }
+                _ => continue,
+            }
+
+            end_sample.cpu_time = (end_sample.cpu_time / ticks_per_second as f64) / (time_now - prev_time) as f64 * 100.0;
+            end_sample.cpu_time = end_sample.cpu_time.clamp(0.0, max_percent);
+
+            prev_time = time_now;
+            pid_entry.entries.push(end_sample);
+
+            prev_sample = sample.clone();
+        }
+        pid_entries.pid_entries.push(pid_entry);
+    }
+
+    /* Order the processes by Total CPU Time per collection time */
+    pid_entries.pid_entries.sort_by(|a, b| (b.total_cpu_time).cmp(&(a.total_cpu_time)));
+
+    if pid_entries.pid_entries.len() > count {
+        pid_entries.pid_entries.truncate(count);
+    }
+
+    Ok(serde_json::to_string(&pid_entries)?)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ProcessTreeNode {
+    pub pid: u64,
+    pub ppid: u64,
+    pub name: String,
+    pub cpu_time: u64,
+    pub group_cpu_time: u64,
+    pub children: Vec<ProcessTreeNode>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ProcessTree {
+    pub collection_time: TimeEnum,
+    pub roots: Vec<ProcessTreeNode>,
+}
+
+fn group_cpu_time(pid: u64, nodes: &HashMap<u64, ProcessTreeNode>, children_of: &HashMap<u64, Vec<u64>>) -> u64 {
+    let own = nodes.get(&pid).map(|n| n.cpu_time).unwrap_or(0);
+    let children_total: u64 = match children_of.get(&pid) {
+        Some(children) => children.iter().map(|child_pid| group_cpu_time(*child_pid, nodes, children_of)).sum(),
+        None => 0,
+    };
+    own + children_total
+}
+
+fn build_tree_node(pid: u64, nodes: &HashMap<u64, ProcessTreeNode>, children_of: &HashMap<u64, Vec<u64>>) -> ProcessTreeNode {
+    let mut node = nodes.get(&pid).cloned().unwrap();
+    node.group_cpu_time = group_cpu_time(pid, nodes, children_of);
+    node.children = match children_of.get(&pid) {
+        Some(children) => children.iter().map(|child_pid| build_tree_node(*child_pid, nodes, children_of)).collect(),
+        None => Vec::new(),
+    };
+    node
+}
+
+/* Re-shapes the flat PID list into a parent->children tree, aggregating each subtree's CPU time
+ * into its ancestors, the way bottom's ppid/group_pids model does. */
+pub fn get_process_tree(values: Vec<Processes>, count: usize) -> Result<String> {
+    /* Build the tree from the full PID list, uncapped, so truncating to `count` roots below
+     * doesn't sever parent/child links that the cap would otherwise hide a parent behind. */
+    let pid_json = get_values_by_pid(values, usize::MAX)?;
+    let pid_entries: PidEntries = serde_json::from_str(&pid_json)?;
+
+    let mut nodes: HashMap<u64, ProcessTreeNode> = HashMap::new();
+    let mut children_of: HashMap<u64, Vec<u64>> = HashMap::new();
+    for entry in &pid_entries.pid_entries {
+        nodes.insert(entry.pid, ProcessTreeNode {
+            pid: entry.pid,
+            ppid: entry.ppid,
+            name: entry.name.clone(),
+            cpu_time: entry.total_cpu_time,
+            group_cpu_time: entry.total_cpu_time,
+            children: Vec::new(),
+        });
+        children_of.entry(entry.ppid).or_insert_with(Vec::new).push(entry.pid);
+    }
+
+    /* A PID is a tree root when its parent wasn't itself captured in this sample */
+    let roots: Vec<u64> = nodes.values()
+        .filter(|n| n.ppid == 0 || !nodes.contains_key(&n.ppid))
+        .map(|n| n.pid)
+        .collect();
+
+    let mut root_nodes: Vec<ProcessTreeNode> = roots.iter().map(|pid| build_tree_node(*pid, &nodes, &children_of)).collect();
+    root_nodes.sort_by(|a, b| (b.group_cpu_time).cmp(&(a.group_cpu_time)));
+
+    if root_nodes.len() > count {
+        root_nodes.truncate(count);
+    }
+
+    let process_tree = ProcessTree {
+        collection_time: pid_entries.collection_time,
+        roots: root_nodes,
+    };
+
+    Ok(serde_json::to_string(&process_tree)?)
+}
+
+/* Only compile the regex when regex mode is actually requested; it is compiled once per call and reused for every entry */
+fn filter_processes(values: Vec<Processes>, filter: Option<String>, use_regex: bool) -> Result<Vec<Processes>> {
+    let filter = match filter {
+        Some(f) if !f.is_empty() => f,
+        _ => return Ok(values),
+    };
+    let re = if use_regex { Some(Regex::new(&filter)?) } else { None };
+
+    Ok(values.into_iter().map(|mut value| {
+        value.entries.retain(|entry| match &re {
+            Some(re) => re.is_match(&entry.name),
+            None => entry.name.contains(&filter),
+        });
+        value
+    }).collect())
+}
+
 impl GetData for Processes {
     fn process_raw_data(&mut self, buffer: Data) -> Result<ProcessedData> {
         let mut processes = Processes::new();
@@ -241,17 +760,41 @@ impl GetData for Processes {
         *TICKS_PER_SECOND.lock().unwrap() = raw_value.ticks_per_second as u64;
         let reader = BufReader::new(raw_value.data.as_bytes());
         processes.time = raw_value.time;
+        let now_secs = match raw_value.time {
+            TimeEnum::DateTime(dt) => dt.timestamp(),
+            TimeEnum::TimeDiff(_) => 0,
+        };
         for line in reader.lines() {
             let line = line?;
             let line_str: Vec<&str> = line.split(';').collect();
+            if line_str.len() != 8 {
+                error!("Skipping process record with unexpected field count: {}", line);
+                continue;
+            }
 
             let name = line_str[0];
             let pid = line_str[1];
             let cpu_time = line_str[2];
+            let mem_usage_bytes = line_str[3];
+            let read_bytes = line_str[4];
+            let write_bytes = line_str[5];
+            let starttime = line_str[6].parse::<u64>()?;
+            let ppid = line_str[7];
+            let running_time = running_time_secs(
+                raw_value.ticks_per_second,
+                raw_value.boot_time,
+                starttime,
+                now_secs,
+            );
             let sample = SampleEntry {
                 name: name.to_string(),
                 pid: pid.parse::<u64>()?,
+                ppid: ppid.parse::<u64>()?,
                 cpu_time: cpu_time.parse::<u64>()?,
+                mem_usage_bytes: mem_usage_bytes.parse::<u64>()?,
+                read_bytes: read_bytes.parse::<u64>()?,
+                write_bytes: write_bytes.parse::<u64>()?,
+                running_time,
             };
             processes.entries.push(sample);
         }
@@ -262,6 +805,11 @@ impl GetData for Processes {
     fn get_calls(&mut self) -> Result<Vec<String>> {
         let mut end_values = Vec::new();
         end_values.push("values".to_string());
+        end_values.push("mem_values".to_string());
+        end_values.push("io_values".to_string());
+        end_values.push("age_values".to_string());
+        end_values.push("pid_values".to_string());
+        end_values.push("tree_values".to_string());
         Ok(end_values)
     }
 
@@ -279,8 +827,23 @@ impl GetData for Processes {
         }
         let (_, req_str) = &param[1];
 
+        let filter = param.iter().find(|(k, _)| k == "filter").map(|(_, v)| v.clone());
+        let use_regex = param.iter().any(|(k, v)| k == "regex" && v == "true");
+        let values = filter_processes(values, filter, use_regex)?;
+
+        let count: usize = param
+            .iter()
+            .find(|(k, _)| k == "count")
+            .and_then(|(_, v)| v.parse::<usize>().ok())
+            .unwrap_or(16);
+
         match req_str.as_str() {
-            "values" => get_values(values.clone()),
+            "values" => get_values(values.clone(), count),
+            "mem_values" => get_mem_values(values.clone(), count),
+            "io_values" => get_io_values(values.clone(), count),
+            "age_values" => get_age_values(values.clone(), count),
+            "pid_values" => get_values_by_pid(values.clone(), count),
+            "tree_values" => get_process_tree(values.clone(), count),
             _ => panic!("Unsupported API"),
         }
     }
@@ -318,8 +881,12 @@ fn init_system_processes() {
 
 #[cfg(test)]
 mod process_test {
-    use super::{Processes, ProcessesRaw};
-    use crate::data::{CollectData, Data, ProcessedData};
+    use super::{
+        filter_processes, get_io_values, get_process_tree, get_values, get_values_by_pid, mem_percent,
+        running_time_secs, EndEntries, PidEntries, ProcessTree, Processes, ProcessesRaw, SampleEntry,
+        TICKS_PER_SECOND,
+    };
+    use crate::data::{CollectData, Data, ProcessedData, TimeEnum};
     use crate::visualizer::GetData;
 
     #[test]
@@ -330,6 +897,340 @@ mod process_test {
         assert!(!processes.data.is_empty());
     }
 
+    #[test]
+    fn test_running_time_secs_zero_ticks_per_second() {
+        assert_eq!(running_time_secs(0, 1_600_000_000, 100, 1_600_000_100), 0);
+    }
+
+    #[test]
+    fn test_running_time_secs_epoch_starttime() {
+        /* boot_time == 0 and starttime_ticks == 0 yields the Unix epoch, which is bogus */
+        assert_eq!(running_time_secs(100, 0, 0, 1_600_000_000), 0);
+    }
+
+    #[test]
+    fn test_running_time_secs_now_before_start() {
+        /* now_secs at or before the process start would otherwise underflow */
+        assert_eq!(running_time_secs(100, 1_600_000_000, 0, 1_600_000_000), 0);
+        assert_eq!(running_time_secs(100, 1_600_000_000, 0, 1_599_999_999), 0);
+    }
+
+    #[test]
+    fn test_running_time_secs_normal() {
+        /* boot_time=1000, starttime_ticks=500 @ 100 ticks/sec -> started at t=1005 */
+        assert_eq!(running_time_secs(100, 1000, 500, 1100), 95);
+    }
+
+    fn sample_entry(name: &str) -> SampleEntry {
+        SampleEntry {
+            name: name.to_string(),
+            pid: 0,
+            ppid: 0,
+            cpu_time: 0,
+            mem_usage_bytes: 0,
+            read_bytes: 0,
+            write_bytes: 0,
+            running_time: 0,
+        }
+    }
+
+    #[test]
+    fn test_filter_processes_substring() {
+        let values = vec![Processes {
+            time: TimeEnum::TimeDiff(0),
+            entries: vec![sample_entry("bash"), sample_entry("redis-server")],
+        }];
+        let filtered = filter_processes(values, Some("redis".to_string()), false).unwrap();
+        let names: Vec<&str> = filtered[0].entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["redis-server"]);
+    }
+
+    #[test]
+    fn test_filter_processes_regex() {
+        let values = vec![Processes {
+            time: TimeEnum::TimeDiff(0),
+            entries: vec![sample_entry("bash"), sample_entry("redis-server")],
+        }];
+        let filtered = filter_processes(values, Some("^red.*".to_string()), true).unwrap();
+        let names: Vec<&str> = filtered[0].entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["redis-server"]);
+    }
+
+    #[test]
+    fn test_filter_processes_rejects_bad_regex() {
+        let values = vec![Processes {
+            time: TimeEnum::TimeDiff(0),
+            entries: vec![sample_entry("bash")],
+        }];
+        assert!(filter_processes(values, Some("(".to_string()), true).is_err());
+    }
+
+    #[test]
+    fn test_mem_percent() {
+        assert_eq!(mem_percent(512, 2048), 25.0);
+    }
+
+    #[test]
+    fn test_mem_percent_zero_total() {
+        /* Guard against dividing by an unreadable/zero total rather than yielding NaN */
+        assert_eq!(mem_percent(512, 0), 0.0);
+    }
+
+    #[test]
+    fn test_get_values_respects_count() {
+        *TICKS_PER_SECOND.lock().unwrap() = 100;
+        let count = 2;
+        let mut entries_zero = Vec::new();
+        let mut entries_one = Vec::new();
+        for i in 0..(count as u64 + 1) {
+            let name = format!("proc{}", i);
+            entries_zero.push(SampleEntry {
+                name: name.clone(),
+                pid: i,
+                ppid: 0,
+                cpu_time: 0,
+                mem_usage_bytes: 0,
+                read_bytes: 0,
+                write_bytes: 0,
+                running_time: 0,
+            });
+            entries_one.push(SampleEntry {
+                name,
+                pid: i,
+                ppid: 0,
+                cpu_time: (i + 1) * 1000,
+                mem_usage_bytes: 0,
+                read_bytes: 0,
+                write_bytes: 0,
+                running_time: 0,
+            });
+        }
+        let values = vec![
+            Processes { time: TimeEnum::TimeDiff(0), entries: entries_zero },
+            Processes { time: TimeEnum::TimeDiff(1), entries: entries_one },
+        ];
+
+        let result = get_values(values, count).unwrap();
+        let end_values: EndEntries = serde_json::from_str(&result).unwrap();
+        assert_eq!(end_values.end_entries.len(), count);
+    }
+
+    #[test]
+    fn test_get_io_values_per_interval_rates() {
+        /* A read burst in the first interval followed by an idle second interval should show up
+         * as two distinct per-interval rates, not get averaged away into a single window rate. */
+        let entry = |read_bytes: u64| SampleEntry {
+            name: "proc0".to_string(),
+            pid: 1,
+            ppid: 0,
+            cpu_time: 0,
+            mem_usage_bytes: 0,
+            read_bytes,
+            write_bytes: 0,
+            running_time: 0,
+        };
+        let values = vec![
+            Processes { time: TimeEnum::TimeDiff(0), entries: vec![entry(1000)] },
+            Processes { time: TimeEnum::TimeDiff(1), entries: vec![entry(5000)] },
+            Processes { time: TimeEnum::TimeDiff(2), entries: vec![entry(5000)] },
+        ];
+
+        let result = get_io_values(values, 16).unwrap();
+        let end_values: EndEntries = serde_json::from_str(&result).unwrap();
+        let proc0 = &end_values.end_entries[0];
+
+        assert_eq!(proc0.io_entries.len(), 2);
+        assert_eq!(proc0.io_entries[0].read_bytes_per_sec, 4000.0);
+        assert_eq!(proc0.io_entries[1].read_bytes_per_sec, 0.0);
+    }
+
+    #[test]
+    fn test_get_values_by_pid_respects_count() {
+        *TICKS_PER_SECOND.lock().unwrap() = 100;
+        let count = 2;
+        let mut entries_zero = Vec::new();
+        let mut entries_one = Vec::new();
+        for i in 0..(count as u64 + 1) {
+            entries_zero.push(SampleEntry {
+                name: format!("proc{}", i),
+                pid: i,
+                ppid: 0,
+                cpu_time: 0,
+                mem_usage_bytes: 0,
+                read_bytes: 0,
+                write_bytes: 0,
+                running_time: 0,
+            });
+            entries_one.push(SampleEntry {
+                name: format!("proc{}", i),
+                pid: i,
+                ppid: 0,
+                cpu_time: (i + 1) * 1000,
+                mem_usage_bytes: 0,
+                read_bytes: 0,
+                write_bytes: 0,
+                running_time: 0,
+            });
+        }
+        let values = vec![
+            Processes { time: TimeEnum::TimeDiff(0), entries: entries_zero },
+            Processes { time: TimeEnum::TimeDiff(1), entries: entries_one },
+        ];
+
+        let result = get_values_by_pid(values, count).unwrap();
+        let pid_entries: PidEntries = serde_json::from_str(&result).unwrap();
+        assert_eq!(pid_entries.pid_entries.len(), count);
+    }
+
+    #[test]
+    fn test_get_values_by_pid_keeps_distinct_pids_with_same_name() {
+        /* Two worker PIDs sharing a `comm` must stay separate buckets, unlike the name-keyed
+         * get_values, which would merge them. */
+        *TICKS_PER_SECOND.lock().unwrap() = 100;
+        let mut worker_a_zero = sample_entry("worker");
+        worker_a_zero.pid = 1;
+        let mut worker_b_zero = sample_entry("worker");
+        worker_b_zero.pid = 2;
+        let mut worker_a = sample_entry("worker");
+        worker_a.pid = 1;
+        worker_a.cpu_time = 1000;
+        let mut worker_b = sample_entry("worker");
+        worker_b.pid = 2;
+        worker_b.cpu_time = 2000;
+
+        let values = vec![
+            Processes { time: TimeEnum::TimeDiff(0), entries: vec![worker_a_zero, worker_b_zero] },
+            Processes { time: TimeEnum::TimeDiff(1), entries: vec![worker_a, worker_b] },
+        ];
+
+        let result = get_values_by_pid(values, 16).unwrap();
+        let pid_entries: PidEntries = serde_json::from_str(&result).unwrap();
+        let mut pids: Vec<u64> = pid_entries.pid_entries.iter().map(|e| e.pid).collect();
+        pids.sort();
+        assert_eq!(pids, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_get_process_tree_sums_parent_and_child() {
+        *TICKS_PER_SECOND.lock().unwrap() = 100;
+        let mut parent = sample_entry("parent");
+        parent.pid = 1;
+        let mut child = sample_entry("child");
+        child.pid = 2;
+        child.ppid = 1;
+
+        let mut parent_later = sample_entry("parent");
+        parent_later.pid = 1;
+        parent_later.cpu_time = 1000;
+        let mut child_later = sample_entry("child");
+        child_later.pid = 2;
+        child_later.ppid = 1;
+        child_later.cpu_time = 500;
+
+        let values = vec![
+            Processes { time: TimeEnum::TimeDiff(0), entries: vec![parent, child] },
+            Processes { time: TimeEnum::TimeDiff(1), entries: vec![parent_later, child_later] },
+        ];
+
+        let result = get_process_tree(values, 16).unwrap();
+        let process_tree: ProcessTree = serde_json::from_str(&result).unwrap();
+        assert_eq!(process_tree.roots.len(), 1);
+        let root = &process_tree.roots[0];
+        assert_eq!(root.pid, 1);
+        assert_eq!(root.children.len(), 1);
+        assert_eq!(root.children[0].pid, 2);
+        /* group_cpu_time must include the child's cpu_time, not just the parent's own */
+        assert_eq!(root.group_cpu_time, root.cpu_time + root.children[0].cpu_time);
+    }
+
+    #[test]
+    fn test_get_process_tree_orphaned_child_becomes_root() {
+        /* A child whose parent wasn't captured in this sample (e.g. filtered out, or exited
+         * before the parent) must surface as its own root rather than being dropped. */
+        *TICKS_PER_SECOND.lock().unwrap() = 100;
+        let mut orphan_zero = sample_entry("orphan");
+        orphan_zero.pid = 2;
+        orphan_zero.ppid = 99; /* pid 99 is not present in this sample */
+        let mut orphan = sample_entry("orphan");
+        orphan.pid = 2;
+        orphan.ppid = 99;
+
+        let values = vec![
+            Processes { time: TimeEnum::TimeDiff(0), entries: vec![orphan_zero] },
+            Processes { time: TimeEnum::TimeDiff(1), entries: vec![orphan] },
+        ];
+
+        let result = get_process_tree(values, 16).unwrap();
+        let process_tree: ProcessTree = serde_json::from_str(&result).unwrap();
+        assert_eq!(process_tree.roots.len(), 1);
+        assert_eq!(process_tree.roots[0].pid, 2);
+    }
+
+    #[test]
+    fn test_get_process_tree_respects_count() {
+        /* count+1 independent parent/child pairs, so there are more roots than `count`
+         * while still exercising group_cpu_time aggregation across a generation. */
+        *TICKS_PER_SECOND.lock().unwrap() = 100;
+        let count = 2;
+        let mut entries_zero = Vec::new();
+        let mut entries_one = Vec::new();
+        for i in 0..(count as u64 + 1) {
+            let parent_pid = i * 2 + 1;
+            let child_pid = i * 2 + 2;
+            entries_zero.push(SampleEntry {
+                name: format!("parent{}", i),
+                pid: parent_pid,
+                ppid: 0,
+                cpu_time: 0,
+                mem_usage_bytes: 0,
+                read_bytes: 0,
+                write_bytes: 0,
+                running_time: 0,
+            });
+            entries_zero.push(SampleEntry {
+                name: format!("child{}", i),
+                pid: child_pid,
+                ppid: parent_pid,
+                cpu_time: 0,
+                mem_usage_bytes: 0,
+                read_bytes: 0,
+                write_bytes: 0,
+                running_time: 0,
+            });
+            entries_one.push(SampleEntry {
+                name: format!("parent{}", i),
+                pid: parent_pid,
+                ppid: 0,
+                cpu_time: (i + 1) * 1000,
+                mem_usage_bytes: 0,
+                read_bytes: 0,
+                write_bytes: 0,
+                running_time: 0,
+            });
+            entries_one.push(SampleEntry {
+                name: format!("child{}", i),
+                pid: child_pid,
+                ppid: parent_pid,
+                cpu_time: (i + 1) * 500,
+                mem_usage_bytes: 0,
+                read_bytes: 0,
+                write_bytes: 0,
+                running_time: 0,
+            });
+        }
+        let values = vec![
+            Processes { time: TimeEnum::TimeDiff(0), entries: entries_zero },
+            Processes { time: TimeEnum::TimeDiff(1), entries: entries_one },
+        ];
+
+        let result = get_process_tree(values, count).unwrap();
+        let process_tree: ProcessTree = serde_json::from_str(&result).unwrap();
+        assert_eq!(process_tree.roots.len(), count);
+
+        let richest_root = &process_tree.roots[0];
+        assert!(richest_root.group_cpu_time > richest_root.cpu_time);
+    }
+
     #[test]
     fn test_process_raw_data() {
         let mut buffer: Vec<Data> = Vec::<Data>::new();